@@ -0,0 +1,75 @@
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::time::Instant;
+
+/// One timestamped measurement collected during `--watch` mode.
+///
+/// `recorded_at` is the monotonic `Instant` the tick fired; `data` holds the
+/// same JSON object `generate_json_output` produces for a one-shot run.
+pub struct Sample {
+    pub recorded_at: Instant,
+    pub data: Value,
+}
+
+/// Bounded ring buffer of samples gathered over a watch session.
+///
+/// Only the most recent `capacity` samples are retained; pushing past the
+/// capacity evicts the oldest. `cleanup` drops the heavy one-shot benchmark
+/// blocks from retained samples so a long watch stays memory-bounded.
+pub struct DataCollector {
+    samples: VecDeque<Sample>,
+    capacity: usize,
+}
+
+impl DataCollector {
+    pub fn new(capacity: usize) -> Self {
+        DataCollector {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Record a sample, evicting the oldest if the buffer is full.
+    pub fn push(&mut self, recorded_at: Instant, data: Value) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(Sample { recorded_at, data });
+    }
+
+    /// Drop the expensive GFLOPS/TFLOPS blocks from every retained sample.
+    ///
+    /// These are only measured on the first tick (or an explicit rebench);
+    /// later samples repeat those figures, so keeping them in the history
+    /// wastes memory without adding information.
+    pub fn cleanup(&mut self) {
+        for sample in &mut self.samples {
+            if let Some(obj) = sample.data.as_object_mut() {
+                obj.remove("gpu");
+                if let Some(cpu) = obj.get_mut("cpu").and_then(Value::as_object_mut) {
+                    cpu.remove("gflops");
+                    cpu.remove("benchmark_duration_seconds");
+                }
+            }
+        }
+    }
+
+    /// The most recently pushed sample, if any.
+    pub fn last(&self) -> Option<&Sample> {
+        self.samples.back()
+    }
+
+    /// The oldest retained sample, if any.
+    pub fn first(&self) -> Option<&Sample> {
+        self.samples.front()
+    }
+
+    /// Number of samples currently retained.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+}
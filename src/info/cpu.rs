@@ -0,0 +1,191 @@
+//! Host CPU, OS and memory information.
+
+use sysinfo::System;
+
+/// A closed clock-frequency range, in kHz.
+pub struct ClockRange {
+    pub min: u64,
+    pub max: u64,
+}
+
+/// Per-logical-CPU scaling state read from cpufreq sysfs.
+pub struct CpuScaling {
+    /// Active scaling governor (e.g. `powersave`, `performance`).
+    pub governor: Option<String>,
+    /// Hardware range: `cpuinfo_min_freq` .. `cpuinfo_max_freq`.
+    pub hardware_limits: ClockRange,
+    /// Currently-configured window: `scaling_min_freq` .. `scaling_max_freq`.
+    pub configured_window: ClockRange,
+}
+
+/// Aggregate clock and governor limits for the package.
+///
+/// `clock_min_limits`/`clock_max_limits` span the per-CPU hardware minimum and
+/// maximum frequencies (`cpuinfo_min_freq`/`cpuinfo_max_freq`) respectively.
+/// A capped scaling window does not show here; it is reported per CPU in
+/// `CpuScaling::configured_window`.
+pub struct CpuLimits {
+    pub clock_min_limits: ClockRange,
+    pub clock_max_limits: ClockRange,
+    pub clock_step: u64,
+    pub governors: Vec<String>,
+}
+
+pub struct CpuInfo {
+    pub os: String,
+    pub os_version: Option<String>,
+    pub arch: Option<String>,
+    pub cpu_count: usize,
+    pub total_memory: u64,
+    pub used_memory: u64,
+    pub total_swap: u64,
+    pub used_swap: u64,
+    /// Per-logical-CPU scaling governor and clock limits (Linux cpufreq).
+    pub per_cpu: Vec<CpuScaling>,
+    /// Aggregate clock/governor limits, when cpufreq is exposed.
+    pub limits: Option<CpuLimits>,
+    /// Whether the hardware exposes an SMT control (simultaneous multithreading).
+    pub smt_capable: bool,
+    /// Whether SMT is currently enabled, when the control is present.
+    pub smt_enabled: Option<bool>,
+}
+
+pub fn get_cpu_info() -> CpuInfo {
+    let mut sys = System::new_all();
+    sys.refresh_all();
+
+    let per_cpu = read_cpu_scaling();
+    let limits = aggregate_limits(&per_cpu);
+    let (smt_capable, smt_enabled) = read_smt();
+
+    CpuInfo {
+        os: System::name().unwrap_or_else(|| std::env::consts::OS.to_string()),
+        os_version: System::os_version(),
+        arch: Some(std::env::consts::ARCH.to_string()),
+        cpu_count: sys.cpus().len(),
+        total_memory: sys.total_memory() / 1024 / 1024,
+        used_memory: sys.used_memory() / 1024 / 1024,
+        total_swap: sys.total_swap() / 1024 / 1024,
+        used_swap: sys.used_swap() / 1024 / 1024,
+        per_cpu,
+        limits,
+        smt_capable,
+        smt_enabled,
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn read_cpu_scaling() -> Vec<CpuScaling> {
+    use std::fs;
+
+    let mut scaling = Vec::new();
+    let mut index = 0;
+    loop {
+        let base = format!("/sys/devices/system/cpu/cpu{}/cpufreq", index);
+        if fs::metadata(&base).is_err() {
+            break;
+        }
+        scaling.push(CpuScaling {
+            governor: fs::read_to_string(format!("{}/scaling_governor", base))
+                .ok()
+                .map(|s| s.trim().to_string()),
+            hardware_limits: ClockRange {
+                min: read_khz(&format!("{}/cpuinfo_min_freq", base)),
+                max: read_khz(&format!("{}/cpuinfo_max_freq", base)),
+            },
+            configured_window: ClockRange {
+                min: read_khz(&format!("{}/scaling_min_freq", base)),
+                max: read_khz(&format!("{}/scaling_max_freq", base)),
+            },
+        });
+        index += 1;
+    }
+    scaling
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_cpu_scaling() -> Vec<CpuScaling> {
+    Vec::new()
+}
+
+#[cfg(target_os = "linux")]
+fn read_khz(path: &str) -> u64 {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|s| s.trim().parse::<u64>().ok())
+        .unwrap_or(0)
+}
+
+/// Collapse the per-CPU ranges into package-level aggregates.
+fn aggregate_limits(per_cpu: &[CpuScaling]) -> Option<CpuLimits> {
+    if per_cpu.is_empty() {
+        return None;
+    }
+
+    let min_lo = per_cpu.iter().map(|c| c.hardware_limits.min).min().unwrap_or(0);
+    let min_hi = per_cpu.iter().map(|c| c.hardware_limits.min).max().unwrap_or(0);
+    let max_lo = per_cpu.iter().map(|c| c.hardware_limits.max).min().unwrap_or(0);
+    let max_hi = per_cpu.iter().map(|c| c.hardware_limits.max).max().unwrap_or(0);
+
+    Some(CpuLimits {
+        clock_min_limits: ClockRange { min: min_lo, max: min_hi },
+        clock_max_limits: ClockRange { min: max_lo, max: max_hi },
+        clock_step: read_available_frequencies_step(),
+        governors: read_available_governors(),
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn read_available_governors() -> Vec<String> {
+    std::fs::read_to_string("/sys/devices/system/cpu/cpu0/cpufreq/scaling_available_governors")
+        .map(|s| s.split_whitespace().map(|g| g.to_string()).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_available_governors() -> Vec<String> {
+    Vec::new()
+}
+
+/// Smallest gap between adjacent available frequencies, if the driver lists them.
+#[cfg(target_os = "linux")]
+fn read_available_frequencies_step() -> u64 {
+    let raw = match std::fs::read_to_string(
+        "/sys/devices/system/cpu/cpu0/cpufreq/scaling_available_frequencies",
+    ) {
+        Ok(raw) => raw,
+        Err(_) => return 0,
+    };
+    let mut freqs: Vec<u64> = raw.split_whitespace().filter_map(|f| f.parse().ok()).collect();
+    freqs.sort_unstable();
+    freqs
+        .windows(2)
+        .map(|w| w[1] - w[0])
+        .min()
+        .unwrap_or(0)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_available_frequencies_step() -> u64 {
+    0
+}
+
+/// `(smt_capable, smt_enabled)` from `/sys/devices/system/cpu/smt/control`.
+#[cfg(target_os = "linux")]
+fn read_smt() -> (bool, Option<bool>) {
+    match std::fs::read_to_string("/sys/devices/system/cpu/smt/control") {
+        Ok(state) => match state.trim() {
+            // No SMT hardware: the control exists but reports it can't be used.
+            "notsupported" => (false, None),
+            "on" => (true, Some(true)),
+            // "off" and the firmware-locked "forceoff" are both SMT-capable.
+            _ => (true, Some(false)),
+        },
+        Err(_) => (false, None),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_smt() -> (bool, Option<bool>) {
+    (false, None)
+}
@@ -0,0 +1,126 @@
+//! Disk and filesystem information.
+//!
+//! Mount geometry comes from the `sysinfo` dependency; read/write throughput is
+//! sampled from `/proc/diskstats` over a short window on Linux so a
+//! storage-bound benchmark can be read alongside the CPU/GPU numbers.
+
+use sysinfo::Disks;
+
+pub struct DiskInfo {
+    pub name: String,
+    pub mount_point: String,
+    pub fs_type: String,
+    pub total_bytes: u64,
+    pub available_bytes: u64,
+    pub used_bytes: u64,
+    pub is_removable: bool,
+}
+
+/// Aggregate bytes read from and written to disk over the sampling window.
+pub struct DiskThroughput {
+    pub read_bytes: u64,
+    pub write_bytes: u64,
+    pub interval_ms: u64,
+}
+
+pub fn get_disk_info() -> Vec<DiskInfo> {
+    let disks = Disks::new_with_refreshed_list();
+    disks
+        .iter()
+        .map(|disk| {
+            let total = disk.total_space();
+            let available = disk.available_space();
+            DiskInfo {
+                name: disk.name().to_string_lossy().to_string(),
+                mount_point: disk.mount_point().to_string_lossy().to_string(),
+                fs_type: disk.file_system().to_string_lossy().to_string(),
+                total_bytes: total,
+                available_bytes: available,
+                used_bytes: total.saturating_sub(available),
+                is_removable: disk.is_removable(),
+            }
+        })
+        .collect()
+}
+
+/// Sample aggregate disk throughput over a short interval.
+///
+/// Returns `None` on platforms where `/proc/diskstats` is unavailable.
+#[cfg(target_os = "linux")]
+pub fn get_disk_throughput() -> Option<DiskThroughput> {
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    // `/proc/diskstats` counts sectors; the kernel uses a fixed 512-byte sector
+    // for these fields regardless of the device's logical block size.
+    const SECTOR_BYTES: u64 = 512;
+    const INTERVAL_MS: u64 = 200;
+
+    let before = read_diskstats()?;
+    sleep(Duration::from_millis(INTERVAL_MS));
+    let after = read_diskstats()?;
+
+    Some(DiskThroughput {
+        read_bytes: after.0.saturating_sub(before.0) * SECTOR_BYTES,
+        write_bytes: after.1.saturating_sub(before.1) * SECTOR_BYTES,
+        interval_ms: INTERVAL_MS,
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn get_disk_throughput() -> Option<DiskThroughput> {
+    None
+}
+
+/// Sum of sectors read and written across the whole disks `is_whole_disk`
+/// recognises (`sd*`, `vd*`, `hd*`, `nvme*`, `mmcblk*`); other devices are skipped.
+#[cfg(target_os = "linux")]
+fn read_diskstats() -> Option<(u64, u64)> {
+    let contents = std::fs::read_to_string("/proc/diskstats").ok()?;
+    let mut read_sectors = 0u64;
+    let mut write_sectors = 0u64;
+    for line in contents.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        // fields: major minor name reads merges read_sectors ... writes merges write_sectors
+        if fields.len() < 10 {
+            continue;
+        }
+        let name = fields[2];
+        // Only total whole disks, never their partitions, or sectors get
+        // double-counted (a namespace plus each of its partitions).
+        if !is_whole_disk(name) {
+            continue;
+        }
+        read_sectors += fields[5].parse::<u64>().unwrap_or(0);
+        write_sectors += fields[9].parse::<u64>().unwrap_or(0);
+    }
+    Some((read_sectors, write_sectors))
+}
+
+/// Whether a `/proc/diskstats` device name is a whole disk (not a partition).
+///
+/// SCSI/virtio disks (`sda`, `vdb`) end in a letter and partition into
+/// trailing-digit names (`sda1`); NVMe namespaces (`nvme0n1`) and eMMC
+/// (`mmcblk0`) partition with a `p<digits>` suffix (`nvme0n1p1`, `mmcblk0p1`).
+#[cfg(target_os = "linux")]
+fn is_whole_disk(name: &str) -> bool {
+    if name.starts_with("nvme") || name.starts_with("mmcblk") {
+        !has_partition_suffix(name)
+    } else if name.starts_with("sd") || name.starts_with("vd") || name.starts_with("hd") {
+        !name.chars().last().map_or(false, |c| c.is_ascii_digit())
+    } else {
+        false
+    }
+}
+
+/// True when `name` ends in a `p<digits>` partition suffix.
+#[cfg(target_os = "linux")]
+fn has_partition_suffix(name: &str) -> bool {
+    match name.rfind('p') {
+        Some(pos) => {
+            let tail = &name[pos + 1..];
+            !tail.is_empty() && tail.chars().all(|c| c.is_ascii_digit())
+        }
+        None => false,
+    }
+}
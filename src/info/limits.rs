@@ -0,0 +1,146 @@
+//! Tunable hardware power envelopes (read-only).
+//!
+//! This reports the ranges a machine *could* be tuned to — GPU PPT/TDP windows
+//! and the battery charge-rate range — without changing anything, so a GPU
+//! TFLOPS result can be read against the board's configured power ceiling.
+//! Nodes that aren't exposed on the running hardware are reported as `null`.
+
+/// An inclusive `min..max` window; either bound is `None` when unavailable.
+pub struct RangeLimit {
+    pub min: Option<u64>,
+    pub max: Option<u64>,
+}
+
+impl RangeLimit {
+    const EMPTY: RangeLimit = RangeLimit { min: None, max: None };
+}
+
+/// GPU power envelopes, in microwatts, with the TDP step granularity.
+pub struct GpuLimits {
+    pub fast_ppt: RangeLimit,
+    pub slow_ppt: RangeLimit,
+    pub tdp: RangeLimit,
+    pub tdp_step: Option<u64>,
+}
+
+/// Battery charge-control envelope, with its step: the throttle levels of
+/// `charge_control_limit`, or the start/end charge thresholds in percent.
+pub struct BatteryLimits {
+    pub charge_rate: RangeLimit,
+    pub charge_step: Option<u64>,
+}
+
+pub struct PowerLimits {
+    pub gpu: GpuLimits,
+    pub battery: BatteryLimits,
+}
+
+#[cfg(target_os = "linux")]
+pub fn get_power_limits() -> PowerLimits {
+    PowerLimits {
+        gpu: read_gpu_limits(),
+        battery: read_battery_limits(),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn get_power_limits() -> PowerLimits {
+    PowerLimits {
+        gpu: GpuLimits {
+            fast_ppt: RangeLimit::EMPTY,
+            slow_ppt: RangeLimit::EMPTY,
+            tdp: RangeLimit::EMPTY,
+            tdp_step: None,
+        },
+        battery: BatteryLimits {
+            charge_rate: RangeLimit::EMPTY,
+            charge_step: None,
+        },
+    }
+}
+
+/// Scan hwmon for the first controller exposing a `power1_cap*` TDP window.
+///
+/// On AMD this is the amdgpu hwmon node; fast/slow PPT are read from the
+/// matching `fast_ppt`/`slow_ppt` driver attributes when the platform exposes
+/// them (e.g. via `ryzen_smu`), and left `null` otherwise.
+#[cfg(target_os = "linux")]
+fn read_gpu_limits() -> GpuLimits {
+    use std::fs;
+
+    let mut limits = GpuLimits {
+        fast_ppt: RangeLimit::EMPTY,
+        slow_ppt: RangeLimit::EMPTY,
+        tdp: RangeLimit::EMPTY,
+        tdp_step: None,
+    };
+
+    if let Ok(dir) = fs::read_dir("/sys/class/hwmon") {
+        for entry in dir.flatten() {
+            let base = entry.path();
+            let min = read_u64(&base.join("power1_cap_min"));
+            let max = read_u64(&base.join("power1_cap_max"));
+            if min.is_none() && max.is_none() {
+                continue;
+            }
+            limits.tdp = RangeLimit { min, max };
+            limits.tdp_step = read_u64(&base.join("power1_cap_step"));
+            limits.fast_ppt = RangeLimit {
+                min: read_u64(&base.join("fast_ppt_min")),
+                max: read_u64(&base.join("fast_ppt_max")),
+            };
+            limits.slow_ppt = RangeLimit {
+                min: read_u64(&base.join("slow_ppt_min")),
+                max: read_u64(&base.join("slow_ppt_max")),
+            };
+            break;
+        }
+    }
+
+    limits
+}
+
+#[cfg(target_os = "linux")]
+fn read_battery_limits() -> BatteryLimits {
+    use std::fs;
+
+    let mut limits = BatteryLimits {
+        charge_rate: RangeLimit::EMPTY,
+        charge_step: None,
+    };
+
+    if let Ok(dir) = fs::read_dir("/sys/class/power_supply") {
+        for entry in dir.flatten() {
+            let base = entry.path();
+
+            // Standard power_supply ABI: `charge_control_limit` ranges from 0
+            // up to `charge_control_limit_max`.
+            let max = read_u64(&base.join("charge_control_limit_max"));
+            // Fall back to the start/end charge-threshold pair (e.g. ThinkPad).
+            let (min, max) = if max.is_none() {
+                (
+                    read_u64(&base.join("charge_control_start_threshold")),
+                    read_u64(&base.join("charge_control_end_threshold")),
+                )
+            } else {
+                (Some(0), max)
+            };
+
+            if min.is_none() && max.is_none() {
+                continue;
+            }
+            // There is no step node in the ABI, so it stays `None`.
+            limits.charge_rate = RangeLimit { min, max };
+            break;
+        }
+    }
+
+    limits
+}
+
+#[cfg(target_os = "linux")]
+fn read_u64(path: &std::path::Path) -> Option<u64> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|s| s.trim().parse::<u64>().ok())
+}
@@ -0,0 +1,7 @@
+pub mod cpu;
+pub mod disk;
+pub mod gpu;
+pub mod limits;
+pub mod network;
+pub mod power;
+pub mod temperature;
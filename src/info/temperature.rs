@@ -0,0 +1,96 @@
+//! Thermal sensor readings.
+//!
+//! On Linux the readings come from the hwmon sysfs tree; on other platforms we
+//! fall back to the sensor components exposed by the `sysinfo` dependency. This
+//! lets users correlate CPU/GPU benchmark TFLOPS with thermal throttling over
+//! the course of a run.
+
+/// A single temperature sensor reading, in degrees Celsius.
+///
+/// `high` and `critical` are the manufacturer thresholds when the sensor
+/// exposes them (the point at which throttling kicks in and the hardware limit,
+/// respectively).
+pub struct TemperatureReading {
+    pub label: String,
+    pub celsius: f32,
+    pub high: Option<f32>,
+    pub critical: Option<f32>,
+}
+
+#[cfg(target_os = "linux")]
+pub fn get_temperature_info() -> Vec<TemperatureReading> {
+    use std::fs;
+
+    let mut readings = Vec::new();
+
+    let hwmon = match fs::read_dir("/sys/class/hwmon") {
+        Ok(dir) => dir,
+        Err(_) => return readings,
+    };
+
+    for entry in hwmon.flatten() {
+        let base = entry.path();
+
+        // The chip name (e.g. "coretemp", "k10temp") prefixes each label so
+        // readings from different controllers stay distinguishable.
+        let chip = fs::read_to_string(base.join("name"))
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default();
+
+        for index in 1..=32 {
+            let input = base.join(format!("temp{}_input", index));
+            let millidegrees = match fs::read_to_string(&input) {
+                Ok(s) => s,
+                Err(_) => continue,
+            };
+            let celsius = match millidegrees.trim().parse::<f32>() {
+                Ok(v) => v / 1000.0,
+                Err(_) => continue,
+            };
+
+            let sensor_label = fs::read_to_string(base.join(format!("temp{}_label", index)))
+                .map(|s| s.trim().to_string())
+                .unwrap_or_else(|_| format!("temp{}", index));
+            let label = if chip.is_empty() {
+                sensor_label
+            } else {
+                format!("{} {}", chip, sensor_label)
+            };
+
+            readings.push(TemperatureReading {
+                label,
+                celsius,
+                high: read_threshold(&base.join(format!("temp{}_max", index))),
+                critical: read_threshold(&base.join(format!("temp{}_crit", index))),
+            });
+        }
+    }
+
+    readings
+}
+
+#[cfg(target_os = "linux")]
+fn read_threshold(path: &std::path::Path) -> Option<f32> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|s| s.trim().parse::<f32>().ok())
+        .map(|v| v / 1000.0)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn get_temperature_info() -> Vec<TemperatureReading> {
+    use sysinfo::Components;
+
+    let components = Components::new_with_refreshed_list();
+    components
+        .iter()
+        .map(|component| TemperatureReading {
+            label: component.label().to_string(),
+            celsius: component.temperature(),
+            // `Component::max` is the highest temperature observed so far, not
+            // a threshold, so no `high` threshold is reported here.
+            high: None,
+            critical: component.critical(),
+        })
+        .collect()
+}
@@ -1,93 +1,395 @@
 mod benchmark;
+mod collector;
 mod helpers;
 mod info;
+mod persist;
 
 use benchmark::{cpu::benchmark_cpu, gpu::benchmark_gpu};
 use clap::{Arg, Command};
 use info::cpu::get_cpu_info;
 use info::gpu::get_gpu_info;
 use info::network::{get_ping, get_public_ip, get_internet_speed};
+use info::disk::{get_disk_info, get_disk_throughput, DiskInfo, DiskThroughput};
+use info::limits::{get_power_limits, PowerLimits};
 use info::power::{get_battery_info, BatteryInfo};
+use info::temperature::{get_temperature_info, TemperatureReading};
+use persist::Profile;
+use collector::DataCollector;
 use serde_json::{json, Value};
 use std::fs::File;
 use std::io::{self, Write};
+use std::thread;
+use std::time::{Duration, Instant};
 use tch::Device;
 use tokio::runtime::Runtime;
 
+/// A single pass of feature collection, shared by the one-shot and watch paths.
+struct Collected {
+    cpu_info: Option<info::cpu::CpuInfo>,
+    cpu_gflops: Option<f64>,
+    cpu_elapsed_time: Option<f64>,
+    battery_info: Option<BatteryInfo>,
+    gpu_results: Option<Vec<Value>>,
+    ping: Option<u32>,
+    public_ip: Option<String>,
+    internet_speed: Option<(f64, f64)>,
+    temperature_readings: Option<Vec<TemperatureReading>>,
+    disk_info: Option<Vec<DiskInfo>>,
+    disk_throughput: Option<DiskThroughput>,
+    power_limits: Option<PowerLimits>,
+}
+
 fn main() -> io::Result<()> {
-    let matches = configure_cli();
+    // A `--load-profile` on the command line seeds the defaults before the
+    // rest of the arguments are parsed, so explicit flags still win over it.
+    let preloaded = preload_profile();
+    let matches = configure_cli(preloaded.as_ref());
+
+    if matches.get_flag("list-profiles") {
+        for variant in persist::list_profiles()? {
+            println!("{}\t{} (#{})", variant.id, variant.name, variant.id_num);
+        }
+        return Ok(());
+    }
+
     let output_format = matches.get_one::<String>("format").unwrap();
     let output_file = matches.get_one::<String>("outputFile");
     let features: Vec<&String> = matches.get_many::<String>("features").unwrap().collect();
+    let cpu_iterations = *matches.get_one::<u64>("cpu-iterations").unwrap();
+    let gpu_iterations = *matches.get_one::<u64>("gpu-iterations").unwrap();
+
+    if let Some(name) = matches.get_one::<String>("save-profile") {
+        let profile = Profile {
+            features: features.iter().map(|f| f.to_string()).collect(),
+            cpu_iterations,
+            gpu_iterations,
+            format: output_format.to_string(),
+        };
+        persist::save_profile(name, &profile)?;
+        println!("Saved profile '{}'", name);
+        return Ok(());
+    }
 
-    let mut cpu_info = None;
-    let mut cpu_gflops = None;
-    let mut cpu_elapsed_time = None;
-    let mut battery_info = None;
-    let mut gpu_results = None;
-    let mut ping = None;
-    let mut public_ip = None;
-    let mut internet_speed = None;
+    if matches.get_flag("watch") {
+        return run_watch(&matches, &features, output_format, output_file, cpu_iterations, gpu_iterations);
+    }
+
+    let collected = collect_features(
+        &features,
+        true,
+        matches.get_flag("limits"),
+        cpu_iterations,
+        gpu_iterations,
+    )?;
+
+    let output = match output_format.as_str() {
+        "json" => generate_json_output(
+            collected.cpu_info.as_ref(),
+            collected.cpu_gflops,
+            collected.cpu_elapsed_time,
+            collected.battery_info.as_ref(),
+            collected.gpu_results.as_ref(),
+            collected.ping,
+            collected.public_ip.as_ref(),
+            collected.internet_speed.as_ref(),
+            collected.temperature_readings.as_deref(),
+            collected.disk_info.as_deref(),
+            collected.disk_throughput.as_ref(),
+            collected.power_limits.as_ref(),
+        )?,
+        _ => generate_plain_output(
+            collected.cpu_info.as_ref(),
+            collected.cpu_gflops,
+            collected.cpu_elapsed_time,
+            collected.battery_info.as_ref(),
+            collected.gpu_results.as_ref(),
+            collected.ping,
+            collected.public_ip.as_ref(),
+            collected.internet_speed.as_ref(),
+            collected.temperature_readings.as_deref(),
+            collected.disk_info.as_deref(),
+            collected.disk_throughput.as_ref(),
+            collected.power_limits.as_ref(),
+        ),
+    };
+
+    write_output(output_file, &output)
+}
+
+/// Collect the selected features once.
+///
+/// `run_benchmarks` gates the expensive GFLOPS/TFLOPS passes: in `--watch`
+/// mode they only run on the first tick (or an explicit rebench cadence),
+/// since `benchmark_cpu`/`benchmark_gpu` are far too slow to run every
+/// interval.
+fn collect_features(
+    features: &[&String],
+    run_benchmarks: bool,
+    include_limits: bool,
+    cpu_iterations: u64,
+    gpu_iterations: u64,
+) -> io::Result<Collected> {
+    let mut collected = Collected {
+        cpu_info: None,
+        cpu_gflops: None,
+        cpu_elapsed_time: None,
+        battery_info: None,
+        gpu_results: None,
+        ping: None,
+        public_ip: None,
+        internet_speed: None,
+        temperature_readings: None,
+        disk_info: None,
+        disk_throughput: None,
+        power_limits: None,
+    };
 
     if features.contains(&&"cpu".to_string()) {
-        let cpu_info_data = get_cpu_info();
-        let (gflops, elapsed_time) = benchmark_cpu(5);
-        cpu_info = Some(cpu_info_data);
-        cpu_gflops = Some(gflops);
-        cpu_elapsed_time = Some(elapsed_time);
+        collected.cpu_info = Some(get_cpu_info());
+        if run_benchmarks {
+            let (gflops, elapsed_time) = benchmark_cpu(cpu_iterations);
+            collected.cpu_gflops = Some(gflops);
+            collected.cpu_elapsed_time = Some(elapsed_time);
+        }
     }
 
-    if features.contains(&&"gpu".to_string()) {
-        let supports_mps = cpu_info.as_ref().map_or(false, |info| {
+    if features.contains(&&"gpu".to_string()) && run_benchmarks {
+        let supports_mps = collected.cpu_info.as_ref().map_or(false, |info| {
             info.arch == Some("arm64".to_string()) && info.os == "macos"
         });
-        gpu_results = if supports_mps {
-            Some(benchmark_mps_gpu()?)
+        collected.gpu_results = if supports_mps {
+            Some(benchmark_mps_gpu(gpu_iterations)?)
         } else {
-            Some(benchmark_cuda_gpus()?)
+            Some(benchmark_cuda_gpus(gpu_iterations)?)
         };
     }
 
     if features.contains(&&"battery".to_string()) {
-        battery_info = Some(get_battery_info());
+        collected.battery_info = Some(get_battery_info());
     }
 
     if features.contains(&&"network".to_string()) {
-        ping = get_ping().ok();
+        collected.ping = get_ping().ok();
         // Create a new Tokio runtime
         let rt = Runtime::new()?;
         // Use the runtime to block on the async function
-        public_ip = rt.block_on(get_public_ip()).ok();
-        internet_speed = rt.block_on(get_internet_speed()).ok();
+        collected.public_ip = rt.block_on(get_public_ip()).ok();
+        collected.internet_speed = rt.block_on(get_internet_speed()).ok();
     }
 
-    let output = match output_format.as_str() {
-        "json" => generate_json_output(
-            cpu_info.as_ref(),
-            cpu_gflops,
-            cpu_elapsed_time,
-            battery_info.as_ref(),
-            gpu_results.as_ref(),
-            ping,
-            public_ip.as_ref(),
-            internet_speed.as_ref(),
-        )?,
-        _ => generate_plain_output(
-            cpu_info.as_ref(),
+    if features.contains(&&"thermal".to_string()) {
+        collected.temperature_readings = Some(get_temperature_info());
+    }
+
+    if features.contains(&&"disk".to_string()) {
+        collected.disk_info = Some(get_disk_info());
+        collected.disk_throughput = get_disk_throughput();
+    }
+
+    if include_limits {
+        collected.power_limits = Some(get_power_limits());
+    }
+
+    Ok(collected)
+}
+
+/// Continuously sample the selected features on a fixed interval.
+///
+/// In JSON mode each tick emits one newline-delimited object so the stream can
+/// be piped into other tools; in plain mode a compact summary is redrawn each
+/// interval. The expensive benchmarks run on the first tick and then again on
+/// the `--rebench` cadence; all other ticks reuse the cheap live metrics.
+fn run_watch(
+    matches: &clap::ArgMatches,
+    features: &[&String],
+    output_format: &str,
+    output_file: Option<&String>,
+    cpu_iterations: u64,
+    gpu_iterations: u64,
+) -> io::Result<()> {
+    let interval = Duration::from_millis(*matches.get_one::<u64>("interval").unwrap());
+    let duration = matches.get_one::<u64>("duration").map(|s| Duration::from_secs(*s));
+    let rebench_every = matches.get_one::<u64>("rebench").copied();
+    let include_limits = matches.get_flag("limits");
+
+    // Keep a bounded history so a long-running watch stays memory-bounded.
+    let mut collector = DataCollector::new(256);
+    let start = Instant::now();
+    let mut tick: u64 = 0;
+
+    // The expensive benchmarks only run on the first tick (or a rebench tick);
+    // carry their results forward so later samples report the real figure
+    // rather than a misleading 0.0.
+    let mut cpu_gflops = None;
+    let mut cpu_elapsed_time = None;
+    let mut gpu_results: Option<Vec<Value>> = None;
+
+    loop {
+        let run_benchmarks = tick == 0 || rebench_every.map_or(false, |n| n > 0 && tick % n == 0);
+        let collected =
+            collect_features(features, run_benchmarks, include_limits, cpu_iterations, gpu_iterations)?;
+        let now = Instant::now();
+
+        if run_benchmarks {
+            cpu_gflops = collected.cpu_gflops;
+            cpu_elapsed_time = collected.cpu_elapsed_time;
+            gpu_results = collected.gpu_results.clone();
+        }
+
+        let json = generate_json_output(
+            collected.cpu_info.as_ref(),
             cpu_gflops,
             cpu_elapsed_time,
-            battery_info.as_ref(),
+            collected.battery_info.as_ref(),
             gpu_results.as_ref(),
-            ping,
-            public_ip.as_ref(),
-            internet_speed.as_ref(),
-        ),
+            collected.ping,
+            collected.public_ip.as_ref(),
+            collected.internet_speed.as_ref(),
+            collected.temperature_readings.as_deref(),
+            collected.disk_info.as_deref(),
+            collected.disk_throughput.as_ref(),
+            collected.power_limits.as_ref(),
+        )?;
+
+        match output_format {
+            "json" => {
+                // Newline-delimited JSON: one compact object per sample.
+                let record: Value = serde_json::from_str(&json)?;
+                let line = serde_json::to_string(&json!({
+                    "elapsed_ms": now.duration_since(start).as_millis() as u64,
+                    "sample": record,
+                }))?;
+                write_watch_line(output_file, &line)?;
+                collector.push(now, record);
+            }
+            _ => {
+                // The plain GPU section benchmarks the devices itself, so only
+                // pass GPU results on benchmark ticks.
+                let summary = generate_plain_output(
+                    collected.cpu_info.as_ref(),
+                    cpu_gflops,
+                    cpu_elapsed_time,
+                    collected.battery_info.as_ref(),
+                    if run_benchmarks { gpu_results.as_ref() } else { None },
+                    collected.ping,
+                    collected.public_ip.as_ref(),
+                    collected.internet_speed.as_ref(),
+                    collected.temperature_readings.as_deref(),
+                    collected.disk_info.as_deref(),
+                    collected.disk_throughput.as_ref(),
+                    collected.power_limits.as_ref(),
+                );
+                if output_file.is_some() {
+                    // A file sink gets each summary appended, like the JSON
+                    // branch; the screen-clearing redraw only makes sense on a
+                    // terminal.
+                    write_watch_line(output_file, summary.trim_end())?;
+                } else {
+                    // Clear the screen and redraw the compact summary each interval.
+                    print!("\x1B[2J\x1B[H{}", summary);
+                    io::stdout().flush()?;
+                }
+                collector.push(now, serde_json::from_str(&json)?);
+            }
+        }
+
+        // Drop the heavy one-shot fields from retained history between ticks.
+        collector.cleanup();
+
+        if let Some(limit) = duration {
+            if now.duration_since(start) >= limit {
+                break;
+            }
+        }
+
+        tick += 1;
+        thread::sleep(interval);
+    }
+
+    emit_watch_summary(&collector, output_format, output_file)
+}
+
+/// Emit a trailing aggregate over the retained watch history.
+fn emit_watch_summary(
+    collector: &DataCollector,
+    output_format: &str,
+    output_file: Option<&String>,
+) -> io::Result<()> {
+    if collector.is_empty() {
+        return Ok(());
+    }
+
+    let (first, last) = match (collector.first(), collector.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return Ok(()),
     };
+    let span_ms = last.recorded_at.duration_since(first.recorded_at).as_millis() as u64;
+
+    match output_format {
+        "json" => {
+            let line = serde_json::to_string(&json!({
+                "summary": {
+                    "samples": collector.len(),
+                    "span_ms": span_ms,
+                    "last": last.data,
+                }
+            }))?;
+            write_watch_line(output_file, &line)
+        }
+        _ => {
+            println!(
+                "=> Watch summary: {} samples over {} ms",
+                collector.len(),
+                span_ms
+            );
+            Ok(())
+        }
+    }
+}
 
-    write_output(output_file, &output)
+/// Append a single line (a JSON record or a plain summary) to the output sink.
+fn write_watch_line(output_file: Option<&String>, line: &str) -> io::Result<()> {
+    if let Some(file_path) = output_file {
+        let mut file = File::options().create(true).append(true).open(file_path)?;
+        writeln!(file, "{}", line)?;
+    } else {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+/// Scan the raw arguments for `--load-profile <name>` and load that profile so
+/// its saved settings can seed the CLI defaults. Returns `None` when the flag
+/// is absent or the named profile cannot be read.
+fn preload_profile() -> Option<Profile> {
+    let mut args = std::env::args();
+    while let Some(arg) = args.next() {
+        if arg == "--load-profile" {
+            let name = args.next()?;
+            return persist::load_profile(&name).ok();
+        }
+    }
+    None
 }
 
-fn configure_cli() -> clap::ArgMatches {
+fn configure_cli(defaults: Option<&Profile>) -> clap::ArgMatches {
+    // Profiles seed the defaults; clap wants `'static` strings, so leak the
+    // small handful we build here (they live for the whole process anyway).
+    let leak = |s: String| -> &'static str { Box::leak(s.into_boxed_str()) };
+    let default_format = leak(
+        defaults
+            .map(|p| p.format.clone())
+            .unwrap_or_else(|| "plain".to_string()),
+    );
+    let default_features = leak(
+        defaults
+            .map(|p| p.features.join(","))
+            .unwrap_or_else(|| "cpu,gpu,battery,network,thermal,disk".to_string()),
+    );
+    let default_cpu_iterations = leak(defaults.map(|p| p.cpu_iterations).unwrap_or(5).to_string());
+    let default_gpu_iterations =
+        leak(defaults.map(|p| p.gpu_iterations).unwrap_or(1000).to_string());
+
     Command::new("System Benchmark")
         .version("1.0")
         .about("Benchmarks CPU and GPU performance, and provides battery information")
@@ -97,7 +399,7 @@ fn configure_cli() -> clap::ArgMatches {
                 .long("format")
                 .value_name("FORMAT")
                 .help("Sets the output format: plain or json")
-                .default_value("plain"),
+                .default_value(default_format),
         )
         .arg(
             Arg::new("outputFile")
@@ -111,10 +413,79 @@ fn configure_cli() -> clap::ArgMatches {
                 .short('e')
                 .long("features")
                 .value_name("FEATURE")
-                .help("Select which benchmarks/features to run/enable: cpu, gpu, battery, network (comma-separated)")
-                .default_value("cpu,gpu,battery,network")
+                .help("Select which benchmarks/features to run/enable: cpu, gpu, battery, network, thermal, disk (comma-separated)")
+                .default_value(default_features)
                 .use_value_delimiter(true),
         )
+        .arg(
+            Arg::new("cpu-iterations")
+                .long("cpu-iterations")
+                .value_name("N")
+                .help("Number of iterations for the CPU benchmark")
+                .value_parser(clap::value_parser!(u64))
+                .default_value(default_cpu_iterations),
+        )
+        .arg(
+            Arg::new("gpu-iterations")
+                .long("gpu-iterations")
+                .value_name("N")
+                .help("Number of iterations for the GPU benchmark")
+                .value_parser(clap::value_parser!(u64))
+                .default_value(default_gpu_iterations),
+        )
+        .arg(
+            Arg::new("save-profile")
+                .long("save-profile")
+                .value_name("NAME")
+                .help("Save the current configuration as a named profile and exit"),
+        )
+        .arg(
+            Arg::new("load-profile")
+                .long("load-profile")
+                .value_name("NAME")
+                .help("Seed the defaults from a previously saved profile"),
+        )
+        .arg(
+            Arg::new("list-profiles")
+                .long("list-profiles")
+                .help("List the saved profiles and exit")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("limits")
+                .long("limits")
+                .help("Report the tunable GPU/TDP and battery power envelopes (read-only)")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("watch")
+                .short('w')
+                .long("watch")
+                .help("Continuously sample the selected features instead of running once")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("interval")
+                .long("interval")
+                .value_name("MS")
+                .help("Milliseconds between samples in --watch mode")
+                .value_parser(clap::value_parser!(u64))
+                .default_value("1000"),
+        )
+        .arg(
+            Arg::new("duration")
+                .long("duration")
+                .value_name("SECONDS")
+                .help("Stop --watch mode after this many seconds (runs until interrupted if unset)")
+                .value_parser(clap::value_parser!(u64)),
+        )
+        .arg(
+            Arg::new("rebench")
+                .long("rebench")
+                .value_name("TICKS")
+                .help("Re-run the expensive GFLOPS/TFLOPS benchmarks every N watch ticks")
+                .value_parser(clap::value_parser!(u64)),
+        )
         .get_matches()
 }
 fn generate_json_output(
@@ -126,6 +497,10 @@ fn generate_json_output(
     ping: Option<u32>,
     public_ip: Option<&String>,
     internet_speed: Option<&(f64, f64)>,
+    temperature_readings: Option<&[TemperatureReading]>,
+    disk_info: Option<&[DiskInfo]>,
+    disk_throughput: Option<&DiskThroughput>,
+    power_limits: Option<&PowerLimits>,
 ) -> Result<String, serde_json::Error> {
     let mut output_json = json!({});
 
@@ -150,6 +525,31 @@ fn generate_json_output(
             "cpu_count": info.cpu_count,
             "gflops": cpu_gflops.unwrap_or(0.0),
             "benchmark_duration_seconds": cpu_elapsed_time.unwrap_or(0.0),
+            "smt_capable": info.smt_capable,
+            "smt_enabled": info.smt_enabled,
+            "limits": info.limits.as_ref().map(|limits| json!({
+                "clock_min_limits": {
+                    "min": limits.clock_min_limits.min,
+                    "max": limits.clock_min_limits.max,
+                },
+                "clock_max_limits": {
+                    "min": limits.clock_max_limits.min,
+                    "max": limits.clock_max_limits.max,
+                },
+                "clock_step": limits.clock_step,
+                "governors": limits.governors,
+            })),
+            "per_cpu": info.per_cpu.iter().map(|cpu| json!({
+                "governor": cpu.governor,
+                "hardware_limits": {
+                    "min": cpu.hardware_limits.min,
+                    "max": cpu.hardware_limits.max,
+                },
+                "configured_window": {
+                    "min": cpu.configured_window.min,
+                    "max": cpu.configured_window.max,
+                },
+            })).collect::<Vec<Value>>(),
         });
     }
 
@@ -166,6 +566,61 @@ fn generate_json_output(
         });
     }
 
+    if let Some(readings) = temperature_readings {
+        output_json["temperature"] = json!(readings
+            .iter()
+            .map(|reading| {
+                json!({
+                    "label": reading.label,
+                    "celsius": reading.celsius,
+                    "high": reading.high,
+                    "critical": reading.critical,
+                })
+            })
+            .collect::<Vec<Value>>());
+    }
+
+    if let Some(disks) = disk_info {
+        output_json["disks"] = json!(disks
+            .iter()
+            .map(|disk| {
+                json!({
+                    "name": disk.name,
+                    "mount_point": disk.mount_point,
+                    "fs_type": disk.fs_type,
+                    "total_bytes": disk.total_bytes,
+                    "available_bytes": disk.available_bytes,
+                    "used_bytes": disk.used_bytes,
+                    "is_removable": disk.is_removable,
+                })
+            })
+            .collect::<Vec<Value>>());
+
+        if let Some(throughput) = disk_throughput {
+            output_json["disks_throughput"] = json!({
+                "read_bytes": throughput.read_bytes,
+                "write_bytes": throughput.write_bytes,
+                "interval_ms": throughput.interval_ms,
+            });
+        }
+    }
+
+    if let Some(limits) = power_limits {
+        let range = |r: &info::limits::RangeLimit| json!({ "min": r.min, "max": r.max });
+        output_json["limits"] = json!({
+            "gpu": {
+                "fast_ppt": range(&limits.gpu.fast_ppt),
+                "slow_ppt": range(&limits.gpu.slow_ppt),
+                "tdp": range(&limits.gpu.tdp),
+                "tdp_step": limits.gpu.tdp_step,
+            },
+            "battery": {
+                "charge_rate": range(&limits.battery.charge_rate),
+                "charge_step": limits.battery.charge_step,
+            },
+        });
+    }
+
     // Group network-related information
     let mut network = json!({});
 
@@ -201,6 +656,10 @@ fn generate_plain_output(
     ping: Option<u32>,
     public_ip: Option<&String>,
     internet_speed: Option<&(f64, f64)>,
+    temperature_readings: Option<&[TemperatureReading]>,
+    disk_info: Option<&[DiskInfo]>,
+    disk_throughput: Option<&DiskThroughput>,
+    power_limits: Option<&PowerLimits>,
 ) -> String {
     let mut output = String::new();
 
@@ -235,6 +694,18 @@ fn generate_plain_output(
         output.push_str(&format_battery_info(battery));
     }
 
+    if let Some(readings) = temperature_readings {
+        output.push_str(&format_temperature_info(readings));
+    }
+
+    if let Some(disks) = disk_info {
+        output.push_str(&format_disk_info(disks, disk_throughput));
+    }
+
+    if let Some(limits) = power_limits {
+        output.push_str(&format_power_limits(limits));
+    }
+
     if let Some(p) = ping {
         output.push_str(&format!("=> Network:\nInternet Ping: {:.2} ms\n", p));
     }
@@ -278,19 +749,130 @@ fn format_cpu_info(
     cpu_gflops: f64,
     cpu_elapsed_time: f64,
 ) -> String {
-    format!(
+    let mut output = format!(
         "=> CPU:\n\
         Architecture: {}\n\
         Count       : {}\n\
         FLOPS       : {:.2} GFLOPS\n\
-        Benchmark duration: {:.2} seconds\n\n",
+        Benchmark duration: {:.2} seconds\n",
         cpu_info.arch.as_deref().unwrap_or("Not available"),
         cpu_info.cpu_count,
         cpu_gflops,
         cpu_elapsed_time
+    );
+
+    if let Some(smt) = cpu_info.smt_enabled {
+        output.push_str(&format!("SMT         : {}\n", if smt { "on" } else { "off" }));
+    } else if cpu_info.smt_capable {
+        output.push_str("SMT         : capable\n");
+    }
+
+    if let Some(limits) = &cpu_info.limits {
+        output.push_str(&format!(
+            "Governors   : {}\n\
+            Clock range : {}-{} kHz\n",
+            if limits.governors.is_empty() {
+                "Not available".to_string()
+            } else {
+                limits.governors.join(", ")
+            },
+            limits.clock_min_limits.min,
+            limits.clock_max_limits.max
+        ));
+    }
+
+    output.push('\n');
+    output
+}
+
+
+fn format_power_limits(limits: &PowerLimits) -> String {
+    fn range(r: &info::limits::RangeLimit) -> String {
+        match (r.min, r.max) {
+            (Some(min), Some(max)) => format!("{}-{}", min, max),
+            (Some(min), None) => format!("{}-", min),
+            (None, Some(max)) => format!("-{}", max),
+            (None, None) => "None".to_string(),
+        }
+    }
+    fn step(s: Option<u64>) -> String {
+        s.map(|v| v.to_string()).unwrap_or_else(|| "None".to_string())
+    }
+
+    format!(
+        "=> Power limits:\n\
+        GPU fast PPT : {}\n\
+        GPU slow PPT : {}\n\
+        GPU TDP      : {} (step {})\n\
+        Charge rate  : {} (step {})\n\n",
+        range(&limits.gpu.fast_ppt),
+        range(&limits.gpu.slow_ppt),
+        range(&limits.gpu.tdp),
+        step(limits.gpu.tdp_step),
+        range(&limits.battery.charge_rate),
+        step(limits.battery.charge_step)
     )
 }
 
+fn format_disk_info(disks: &[DiskInfo], throughput: Option<&DiskThroughput>) -> String {
+    let mut output = String::from("=> Disks:\n");
+    for disk in disks {
+        output.push_str(&format!(
+            "{:<16} {:<16} {:>8} used / {} total ({}){}\n",
+            disk.name,
+            disk.mount_point,
+            format_bytes(disk.used_bytes),
+            format_bytes(disk.total_bytes),
+            disk.fs_type,
+            if disk.is_removable { " [removable]" } else { "" }
+        ));
+    }
+    if let Some(t) = throughput {
+        let secs = t.interval_ms as f64 / 1000.0;
+        output.push_str(&format!(
+            "Throughput      : read {}/s, write {}/s\n",
+            format_bytes((t.read_bytes as f64 / secs) as u64),
+            format_bytes((t.write_bytes as f64 / secs) as u64)
+        ));
+    }
+    output.push('\n');
+    output
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+fn format_temperature_info(readings: &[TemperatureReading]) -> String {
+    let mut output = String::from("=> Temperature:\n");
+    if readings.is_empty() {
+        output.push_str("No sensors available\n\n");
+        return output;
+    }
+    for reading in readings {
+        let high = reading
+            .high
+            .map(|v| format!("{:.1}", v))
+            .unwrap_or_else(|| "None".to_string());
+        let critical = reading
+            .critical
+            .map(|v| format!("{:.1}", v))
+            .unwrap_or_else(|| "None".to_string());
+        output.push_str(&format!(
+            "{:<20}: {:.1} °C (high {}, crit {})\n",
+            reading.label, reading.celsius, high, critical
+        ));
+    }
+    output.push('\n');
+    output
+}
 
 fn format_battery_info(battery_info: &BatteryInfo) -> String {
     let charge = if battery_info.charge_percent.is_some() {
@@ -317,8 +899,8 @@ fn format_battery_info(battery_info: &BatteryInfo) -> String {
     )
 }
 
-fn benchmark_mps_gpu() -> io::Result<Vec<serde_json::Value>> {
-    let (gpu_tflops, gpu_elapsed_time) = benchmark_gpu(Device::Mps, 1000);
+fn benchmark_mps_gpu(iterations: u64) -> io::Result<Vec<serde_json::Value>> {
+    let (gpu_tflops, gpu_elapsed_time) = benchmark_gpu(Device::Mps, iterations);
     Ok(vec![json!({
         "device": "MPS",
         "tflops": gpu_tflops,
@@ -326,12 +908,12 @@ fn benchmark_mps_gpu() -> io::Result<Vec<serde_json::Value>> {
     })])
 }
 
-fn benchmark_cuda_gpus() -> io::Result<Vec<serde_json::Value>> {
+fn benchmark_cuda_gpus(iterations: u64) -> io::Result<Vec<serde_json::Value>> {
     let gpu_infos = get_gpu_info();
     let mut gpu_results = Vec::new();
 
     for (index, info) in gpu_infos.into_iter().enumerate() {
-        let (gpu_tflops, gpu_elapsed_time) = benchmark_gpu(Device::Cuda(index), 1000);
+        let (gpu_tflops, gpu_elapsed_time) = benchmark_gpu(Device::Cuda(index), iterations);
         gpu_results.push(json!({
             "device_id": info.device_id,
             "device": format!("{:?}", info.device),
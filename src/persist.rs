@@ -0,0 +1,181 @@
+//! Named benchmark profiles saved to disk.
+//!
+//! A profile captures everything that defines a run — the selected features,
+//! the benchmark iteration counts and the output format — so a team can re-run
+//! an identical configuration across machines and get comparable results. Each
+//! profile is one JSON file under the config dir; a `variants.json` index keeps
+//! a stable id per name so several variants of the same machine profile can
+//! coexist.
+
+use serde_json::{json, Value};
+use std::fs;
+use std::io::{self, Error, ErrorKind};
+use std::path::{Path, PathBuf};
+
+/// A saved run configuration.
+pub struct Profile {
+    pub features: Vec<String>,
+    pub cpu_iterations: u64,
+    pub gpu_iterations: u64,
+    pub format: String,
+}
+
+/// One entry in the profile index.
+pub struct VariantInfo {
+    pub id: String,
+    pub name: String,
+    pub id_num: u32,
+}
+
+impl Profile {
+    fn to_json(&self) -> Value {
+        json!({
+            "features": self.features,
+            "cpu_iterations": self.cpu_iterations,
+            "gpu_iterations": self.gpu_iterations,
+            "format": self.format,
+        })
+    }
+
+    fn from_json(value: &Value) -> Option<Profile> {
+        Some(Profile {
+            features: value
+                .get("features")?
+                .as_array()?
+                .iter()
+                .filter_map(|f| f.as_str().map(str::to_string))
+                .collect(),
+            cpu_iterations: value.get("cpu_iterations")?.as_u64()?,
+            gpu_iterations: value.get("gpu_iterations")?.as_u64()?,
+            format: value.get("format")?.as_str()?.to_string(),
+        })
+    }
+}
+
+/// `$XDG_CONFIG_HOME/quick-stats` (falling back to `$HOME/.config/quick-stats`).
+fn config_dir() -> io::Result<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))
+        .ok_or_else(|| Error::new(ErrorKind::NotFound, "no config directory available"))?;
+    Ok(base.join("quick-stats"))
+}
+
+fn profile_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join("profiles").join(format!("{}.json", name))
+}
+
+fn index_path(dir: &Path) -> PathBuf {
+    dir.join("variants.json")
+}
+
+/// Next free variant id: one past the current maximum, or 0 when empty.
+fn next_id_num(index: &[VariantInfo]) -> u32 {
+    index.iter().map(|v| v.id_num).max().map_or(0, |m| m + 1)
+}
+
+/// Persist `profile` under `name`, adding an index entry on its first save.
+pub fn save_profile(name: &str, profile: &Profile) -> io::Result<()> {
+    let dir = config_dir()?;
+    fs::create_dir_all(dir.join("profiles"))?;
+
+    let path = profile_path(&dir, name);
+    fs::write(&path, serde_json::to_string_pretty(&profile.to_json())?)?;
+
+    let mut index = read_index(&dir)?;
+    if !index.iter().any(|v| v.name == name) {
+        let id_num = next_id_num(&index);
+        index.push(VariantInfo {
+            id: format!("{}-{}", name, id_num),
+            name: name.to_string(),
+            id_num,
+        });
+        write_index(&dir, &index)?;
+    }
+
+    Ok(())
+}
+
+/// Load the profile stored under `name`.
+pub fn load_profile(name: &str) -> io::Result<Profile> {
+    let dir = config_dir()?;
+    let raw = fs::read_to_string(profile_path(&dir, name))?;
+    let value: Value = serde_json::from_str(&raw)?;
+    Profile::from_json(&value)
+        .ok_or_else(|| Error::new(ErrorKind::InvalidData, "malformed profile"))
+}
+
+/// List every saved profile variant.
+pub fn list_profiles() -> io::Result<Vec<VariantInfo>> {
+    read_index(&config_dir()?)
+}
+
+fn read_index(dir: &Path) -> io::Result<Vec<VariantInfo>> {
+    let path = index_path(dir);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let value: Value = serde_json::from_str(&fs::read_to_string(path)?)?;
+    let variants = value
+        .as_array()
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|entry| {
+                    Some(VariantInfo {
+                        id: entry.get("id")?.as_str()?.to_string(),
+                        name: entry.get("name")?.as_str()?.to_string(),
+                        id_num: entry.get("id_num")?.as_u64()? as u32,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(variants)
+}
+
+fn write_index(dir: &Path, index: &[VariantInfo]) -> io::Result<()> {
+    let entries: Vec<Value> = index
+        .iter()
+        .map(|v| json!({ "id": v.id, "name": v.name, "id_num": v.id_num }))
+        .collect();
+    fs::write(index_path(dir), serde_json::to_string_pretty(&json!(entries))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_round_trips_through_json() {
+        let profile = Profile {
+            features: vec!["cpu".to_string(), "gpu".to_string()],
+            cpu_iterations: 5,
+            gpu_iterations: 1000,
+            format: "json".to_string(),
+        };
+
+        let restored = Profile::from_json(&profile.to_json()).expect("valid profile");
+
+        assert_eq!(restored.features, profile.features);
+        assert_eq!(restored.cpu_iterations, profile.cpu_iterations);
+        assert_eq!(restored.gpu_iterations, profile.gpu_iterations);
+        assert_eq!(restored.format, profile.format);
+    }
+
+    #[test]
+    fn from_json_rejects_missing_fields() {
+        assert!(Profile::from_json(&json!({ "format": "plain" })).is_none());
+    }
+
+    #[test]
+    fn id_num_is_one_past_the_maximum() {
+        assert_eq!(next_id_num(&[]), 0);
+
+        let index = vec![
+            VariantInfo { id: "a-0".to_string(), name: "a".to_string(), id_num: 0 },
+            VariantInfo { id: "b-3".to_string(), name: "b".to_string(), id_num: 3 },
+        ];
+        assert_eq!(next_id_num(&index), 4);
+    }
+}